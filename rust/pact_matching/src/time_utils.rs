@@ -1,6 +1,10 @@
 use nom::types::CompleteStr;
 use nom::digit1;
 use itertools::Itertools;
+use chrono_tz::Tz;
+use std::str::FromStr;
+use chrono::{DateTime, FixedOffset, Offset, TimeZone, Weekday};
+use chrono::format::Parsed;
 
 //a	Am/pm marker	Text	PM
 //H	Hour in day (0-23)	Number	0
@@ -26,7 +30,55 @@ pub enum DateTimePatternToken {
   DayInMonth,
   DayOfWeekInMonth,
   DayName,
-  DayOfWeek
+  DayOfWeek,
+  Hour0To23,
+  Hour1To24,
+  Hour0To11,
+  Hour1To12,
+  Minute,
+  Second,
+  FractionalSecond,
+  AmPm,
+  GeneralTimeZone,
+  RFC822TimeZone,
+  Iso8601TimeZone(usize),
+  Optional(Vec<DateTimePatternToken>),
+  Quarter,
+  LocalizedDayOfWeek
+}
+
+/// Selects which Java date/time symbol table a pattern is interpreted against. The two APIs disagree
+/// on what `u` means, so the flavor has to be threaded through parsing to resolve it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatFlavor {
+  /// The legacy `java.text.SimpleDateFormat` table, where `u` is the ISO-8601 numeric day of week.
+  SimpleDateFormat,
+  /// The `java.time.format.DateTimeFormatter` table, where `u` is the year.
+  DateTimeFormatter
+}
+
+impl Default for FormatFlavor {
+  fn default() -> Self {
+    FormatFlavor::SimpleDateFormat
+  }
+}
+
+/// Bundles the knobs that influence how a pattern is matched against a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchOptions {
+  pub flavor: FormatFlavor,
+  /// When set, tolerates extra whitespace instead of requiring a strict character-for-character
+  /// match - mirrors dtparse's fuzzy mode. Whitespace is skipped ahead of each pattern field, each
+  /// whitespace character of a literal matches a run of one or more whitespace characters, and
+  /// trailing whitespace is ignored. Whitespace ahead of a literal's non-whitespace character is
+  /// not skipped, so that character must come next in the value.
+  pub lenient: bool
+}
+
+impl Default for MatchOptions {
+  fn default() -> Self {
+    MatchOptions { flavor: FormatFlavor::default(), lenient: false }
+  }
 }
 
 fn is_digit(ch: char) -> bool {
@@ -68,6 +120,53 @@ fn validate_day_of_week(m: CompleteStr) -> Result<CompleteStr, String> {
   validate_number(m, "day of week".into(), 1, 7)
 }
 
+fn validate_hour_0_23(m: CompleteStr) -> Result<CompleteStr, String> {
+  validate_number(m, "hour".into(), 0, 23)
+}
+
+fn validate_hour_1_24(m: CompleteStr) -> Result<CompleteStr, String> {
+  validate_number(m, "hour".into(), 1, 24)
+}
+
+fn validate_hour_0_11(m: CompleteStr) -> Result<CompleteStr, String> {
+  validate_number(m, "hour".into(), 0, 11)
+}
+
+fn validate_hour_1_12(m: CompleteStr) -> Result<CompleteStr, String> {
+  validate_number(m, "hour".into(), 1, 12)
+}
+
+fn validate_minute(m: CompleteStr) -> Result<CompleteStr, String> {
+  validate_number(m, "minute".into(), 0, 59)
+}
+
+fn validate_second(m: CompleteStr) -> Result<CompleteStr, String> {
+  validate_number(m, "second".into(), 0, 59)
+}
+
+fn validate_offset_hours(m: CompleteStr) -> Result<CompleteStr, String> {
+  validate_number(m, "timezone hour offset".into(), 0, 23)
+}
+
+fn validate_offset_minutes(m: CompleteStr) -> Result<CompleteStr, String> {
+  validate_number(m, "timezone minute offset".into(), 0, 59)
+}
+
+fn validate_quarter(m: CompleteStr) -> Result<CompleteStr, String> {
+  validate_number(m, "quarter".into(), 1, 4)
+}
+
+fn is_zone_name_char(ch: char) -> bool {
+  ch.is_alphanumeric() || ch == '_' || ch == '/' || ch == '+' || ch == '-'
+}
+
+fn validate_zone_name(m: CompleteStr) -> Result<CompleteStr, String> {
+  match Tz::from_str(m.0) {
+    Ok(_) => Ok(m),
+    Err(err) => Err(err)
+  }
+}
+
 named!(era_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::Era, many1!(char!('G'))));
 named!(week_in_year_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::WeekInYear, many1!(char!('w'))));
 named!(week_in_month_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::WeekInMonth, many1!(char!('W'))));
@@ -75,11 +174,34 @@ named!(day_in_year_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimeP
 named!(day_in_month_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::DayInMonth, many1!(char!('d'))));
 named!(day_of_week_in_month_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::DayOfWeekInMonth, many1!(char!('F'))));
 named!(day_name_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::DayName, many1!(char!('E'))));
-named!(day_of_week_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::DayOfWeek, many1!(char!('u'))));
+// `u` means "ISO day of week" under SimpleDateFormat but "year" under DateTimeFormatter.
+named_args!(day_of_week_or_year_pattern(flavor: FormatFlavor) <CompleteStr, DateTimePatternToken>, value!(
+  match flavor {
+    FormatFlavor::SimpleDateFormat => DateTimePatternToken::DayOfWeek,
+    FormatFlavor::DateTimeFormatter => DateTimePatternToken::Year
+  },
+  many1!(char!('u'))
+));
+named!(quarter_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::Quarter, many1!(is_a!("Qq"))));
+named!(localized_day_of_week_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::LocalizedDayOfWeek, many1!(is_a!("ec"))));
 named!(year_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::Year, many1!(is_a!("yY"))));
 named!(month_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::Month, many1!(is_a!("ML"))));
+named!(hour_0_23_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::Hour0To23, many1!(char!('H'))));
+named!(hour_1_24_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::Hour1To24, many1!(char!('k'))));
+named!(hour_0_11_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::Hour0To11, many1!(char!('K'))));
+named!(hour_1_12_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::Hour1To12, many1!(char!('h'))));
+named!(minute_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::Minute, many1!(char!('m'))));
+named!(second_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::Second, many1!(char!('s'))));
+named!(fractional_second_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::FractionalSecond, many1!(char!('S'))));
+named!(am_pm_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::AmPm, many1!(char!('a'))));
+named!(general_time_zone_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::GeneralTimeZone, many1!(char!('z'))));
+named!(rfc822_time_zone_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::RFC822TimeZone, many1!(char!('Z'))));
+named!(iso8601_time_zone_pattern <CompleteStr, DateTimePatternToken>, do_parse!(
+  t: many1!(char!('X'))
+  >> (DateTimePatternToken::Iso8601TimeZone(t.len()))
+));
 named!(text_pattern <CompleteStr, DateTimePatternToken>, do_parse!(
-  t: many1!(none_of!("GyYMLwWdDFEu'"))
+  t: many1!(none_of!("GyYMLwWdDFEu'HkKhmsSazZX[]Qqec"))
   >> (DateTimePatternToken::Text(t))
 ));
 named!(quoted_text_pattern <CompleteStr, DateTimePatternToken>, do_parse!(
@@ -91,8 +213,13 @@ named!(quoted_text_pattern <CompleteStr, DateTimePatternToken>, do_parse!(
     .join("").chars().collect()))
 ));
 named!(quote_pattern <CompleteStr, DateTimePatternToken>, value!(DateTimePatternToken::Text("'".chars().collect()), tag!("''")));
-named!(parse_pattern <CompleteStr, Vec<DateTimePatternToken> >, do_parse!(
-  v: many0!(alt!(
+named_args!(optional_pattern(flavor: FormatFlavor) <CompleteStr, DateTimePatternToken>, do_parse!(
+  char!('[')
+  >> v: many0!(call!(pattern_token, flavor))
+  >> char!(']')
+  >> (DateTimePatternToken::Optional(v))
+));
+named_args!(pattern_token(flavor: FormatFlavor) <CompleteStr, DateTimePatternToken>, alt!(
     era_pattern |
     year_pattern |
     month_pattern |
@@ -102,12 +229,34 @@ named!(parse_pattern <CompleteStr, Vec<DateTimePatternToken> >, do_parse!(
     day_in_month_pattern |
     day_of_week_in_month_pattern |
     day_name_pattern |
-    day_of_week_pattern |
+    call!(day_of_week_or_year_pattern, flavor) |
+    quarter_pattern |
+    localized_day_of_week_pattern |
+    hour_0_23_pattern |
+    hour_1_24_pattern |
+    hour_0_11_pattern |
+    hour_1_12_pattern |
+    minute_pattern |
+    second_pattern |
+    fractional_second_pattern |
+    am_pm_pattern |
+    general_time_zone_pattern |
+    rfc822_time_zone_pattern |
+    iso8601_time_zone_pattern |
+    call!(optional_pattern, flavor) |
     quoted_text_pattern |
     quote_pattern |
-    text_pattern)) >> (v)
+    text_pattern
+));
+named_args!(parse_pattern_with_flavor(flavor: FormatFlavor) <CompleteStr, Vec<DateTimePatternToken> >, do_parse!(
+  v: many0!(call!(pattern_token, flavor)) >> (v)
 ));
 
+#[cfg(test)]
+fn parse_pattern(input: CompleteStr) -> nom::IResult<CompleteStr, Vec<DateTimePatternToken>> {
+  parse_pattern_with_flavor(input, FormatFlavor::SimpleDateFormat)
+}
+
 named!(era <CompleteStr, CompleteStr>, alt!(tag_no_case!("ad") | tag_no_case!("bc")));
 named!(month_text <CompleteStr, CompleteStr>, alt!(
   tag_no_case!("january")   | tag_no_case!("jan") |
@@ -130,6 +279,60 @@ named!(week_in_month <CompleteStr, CompleteStr>, map_res!(take_while_m_n!(1, 2,
 named!(day_in_year <CompleteStr, CompleteStr>, map_res!(take_while_m_n!(1, 2, is_digit), validate_day_in_year));
 named!(day_in_month <CompleteStr, CompleteStr>, map_res!(take_while_m_n!(1, 2, is_digit), validate_day_in_month));
 named!(day_of_week <CompleteStr, CompleteStr>, map_res!(take_while_m_n!(1, 1, is_digit), validate_day_of_week));
+named!(quarter_numeric <CompleteStr, CompleteStr>, map_res!(take_while_m_n!(1, 2, is_digit), validate_quarter));
+named!(quarter_text <CompleteStr, CompleteStr>, alt!(
+  tag_no_case!("1st quarter") | tag_no_case!("2nd quarter") | tag_no_case!("3rd quarter") | tag_no_case!("4th quarter") |
+  tag_no_case!("Q1") | tag_no_case!("Q2") | tag_no_case!("Q3") | tag_no_case!("Q4")
+));
+named!(quarter <CompleteStr, CompleteStr>, alt!(quarter_text | quarter_numeric));
+named!(hour_0_23 <CompleteStr, CompleteStr>, map_res!(take_while_m_n!(1, 2, is_digit), validate_hour_0_23));
+named!(hour_1_24 <CompleteStr, CompleteStr>, map_res!(take_while_m_n!(1, 2, is_digit), validate_hour_1_24));
+named!(hour_0_11 <CompleteStr, CompleteStr>, map_res!(take_while_m_n!(1, 2, is_digit), validate_hour_0_11));
+named!(hour_1_12 <CompleteStr, CompleteStr>, map_res!(take_while_m_n!(1, 2, is_digit), validate_hour_1_12));
+named!(minute <CompleteStr, CompleteStr>, map_res!(take_while_m_n!(1, 2, is_digit), validate_minute));
+named!(second <CompleteStr, CompleteStr>, map_res!(take_while_m_n!(1, 2, is_digit), validate_second));
+named!(fractional_second <CompleteStr, CompleteStr>, take_while_m_n!(1, 9, is_digit));
+named!(am_pm <CompleteStr, CompleteStr>, alt!(tag_no_case!("am") | tag_no_case!("pm")));
+
+named!(offset_sign <CompleteStr, char>, alt!(char!('+') | char!('-')));
+named!(offset_hh <CompleteStr, CompleteStr>, map_res!(take_while_m_n!(2, 2, is_digit), validate_offset_hours));
+named!(offset_mm <CompleteStr, CompleteStr>, map_res!(take_while_m_n!(2, 2, is_digit), validate_offset_minutes));
+
+// ±HHMM, e.g. -0700
+named!(rfc822_zone <CompleteStr, CompleteStr>, recognize!(tuple!(offset_sign, offset_hh, offset_mm)));
+
+// ±HH, ±HHMM or ±HH:MM (selected by the number of X chars in the pattern), plus a literal Z for zero offset
+named!(iso8601_zone_1 <CompleteStr, CompleteStr>, alt!(tag!("Z") | recognize!(tuple!(offset_sign, offset_hh))));
+named!(iso8601_zone_2 <CompleteStr, CompleteStr>, alt!(tag!("Z") | recognize!(tuple!(offset_sign, offset_hh, offset_mm))));
+named!(iso8601_zone_3 <CompleteStr, CompleteStr>, alt!(tag!("Z") | recognize!(tuple!(offset_sign, offset_hh, char!(':'), offset_mm))));
+
+fn iso8601_zone(input: CompleteStr, num_chars: usize) -> nom::IResult<CompleteStr, CompleteStr> {
+  match num_chars {
+    1 => iso8601_zone_1(input),
+    2 => iso8601_zone_2(input),
+    _ => iso8601_zone_3(input)
+  }
+}
+
+// GMT±HH:MM or UTC±HH, e.g. GMT-08:00, UTC+3
+named!(gmt_offset <CompleteStr, CompleteStr>, recognize!(tuple!(
+  alt!(tag_no_case!("GMT") | tag_no_case!("UTC")),
+  offset_sign,
+  take_while_m_n!(1, 2, is_digit),
+  opt!(tuple!(char!(':'), take_while_m_n!(2, 2, is_digit)))
+)));
+// a short zone abbreviation, e.g. PST, EST
+named!(zone_abbreviation <CompleteStr, CompleteStr>, alt!(
+  tag_no_case!("PST") | tag_no_case!("PDT") |
+  tag_no_case!("MST") | tag_no_case!("MDT") |
+  tag_no_case!("CST") | tag_no_case!("CDT") |
+  tag_no_case!("EST") | tag_no_case!("EDT") |
+  tag_no_case!("UTC") | tag_no_case!("GMT")
+));
+// a full IANA zone name, e.g. America/New_York, validated against chrono-tz; display names such
+// as "Pacific Standard Time" are not accepted
+named!(zone_name <CompleteStr, CompleteStr>, map_res!(take_while1!(is_zone_name_char), validate_zone_name));
+named!(general_time_zone <CompleteStr, CompleteStr>, alt!(gmt_offset | zone_abbreviation | zone_name));
 named_args!(text<'a>(t: &'a Vec<char>) <CompleteStr<'a>, CompleteStr<'a>>, tag!(t.iter().collect::<String>().as_str()));
 named!(day_of_week_name <CompleteStr, CompleteStr>, alt!(
   tag_no_case!("sunday")    | tag_no_case!("sun") |
@@ -140,38 +343,376 @@ named!(day_of_week_name <CompleteStr, CompleteStr>, alt!(
   tag_no_case!("friday")    | tag_no_case!("fri") |
   tag_no_case!("saturday")  | tag_no_case!("sat")
 ));
+named!(localized_day_of_week <CompleteStr, CompleteStr>, alt!(day_of_week_name | day_of_week));
+
+fn match_token<'a>(buffer: CompleteStr<'a>, token: &'a DateTimePatternToken) -> Result<(CompleteStr<'a>, CompleteStr<'a>), String> {
+  match token {
+    DateTimePatternToken::Era => era(buffer),
+    DateTimePatternToken::Year => digit1(buffer),
+    DateTimePatternToken::WeekInYear => week_in_year(buffer),
+    DateTimePatternToken::WeekInMonth => week_in_month(buffer),
+    DateTimePatternToken::DayInYear => day_in_year(buffer),
+    DateTimePatternToken::DayInMonth => day_in_month(buffer),
+    DateTimePatternToken::Month => month(buffer),
+    DateTimePatternToken::Text(t) => text(buffer, t),
+    DateTimePatternToken::DayOfWeekInMonth => digit1(buffer),
+    DateTimePatternToken::DayName => day_of_week_name(buffer),
+    DateTimePatternToken::DayOfWeek => day_of_week(buffer),
+    DateTimePatternToken::Hour0To23 => hour_0_23(buffer),
+    DateTimePatternToken::Hour1To24 => hour_1_24(buffer),
+    DateTimePatternToken::Hour0To11 => hour_0_11(buffer),
+    DateTimePatternToken::Hour1To12 => hour_1_12(buffer),
+    DateTimePatternToken::Minute => minute(buffer),
+    DateTimePatternToken::Second => second(buffer),
+    DateTimePatternToken::FractionalSecond => fractional_second(buffer),
+    DateTimePatternToken::AmPm => am_pm(buffer),
+    DateTimePatternToken::GeneralTimeZone => general_time_zone(buffer),
+    DateTimePatternToken::RFC822TimeZone => rfc822_zone(buffer),
+    DateTimePatternToken::Iso8601TimeZone(num_chars) => iso8601_zone(buffer, *num_chars),
+    DateTimePatternToken::Quarter => quarter(buffer),
+    DateTimePatternToken::LocalizedDayOfWeek => localized_day_of_week(buffer),
+    DateTimePatternToken::Optional(_) =>
+      return Err("optional pattern sections are resolved by the caller, not match_token".to_string())
+  }.map_err(|err| format!("{:?}", err))
+}
+
+fn skip_leading_whitespace(buffer: CompleteStr) -> CompleteStr {
+  CompleteStr(buffer.0.trim_start_matches(|ch: char| ch.is_whitespace()))
+}
+
+// In lenient mode, a literal `Text` token matches whitespace insensitively: each whitespace
+// character baked into the pattern (e.g. the space in a ", " separator) consumes a run of
+// one-or-more whitespace characters from the value, so "Jul 4,  '01" still matches the single
+// space coming from the ", " literal in "MMM d, ''yy". Non-whitespace characters still have to
+// match exactly.
+fn match_text_lenient<'a>(buffer: CompleteStr<'a>, t: &Vec<char>) -> Result<(CompleteStr<'a>, CompleteStr<'a>), String> {
+  let start = buffer.0;
+  let mut rest = start;
+  for &ch in t.iter() {
+    if ch.is_whitespace() {
+      let trimmed = rest.trim_start_matches(|c: char| c.is_whitespace());
+      if trimmed.len() == rest.len() {
+        return Err(format!("Expected whitespace but found {:?}", rest));
+      }
+      rest = trimmed;
+    } else {
+      let mut chars = rest.chars();
+      match chars.next() {
+        Some(c) if c == ch => rest = chars.as_str(),
+        _ => return Err(format!("Expected '{}' but found {:?}", ch, rest))
+      }
+    }
+  }
+  let matched_len = start.len() - rest.len();
+  Ok((CompleteStr(rest), CompleteStr(&start[..matched_len])))
+}
+
+fn is_text_token(token: &DateTimePatternToken) -> bool {
+  match token {
+    DateTimePatternToken::Text(_) => true,
+    _ => false
+  }
+}
+
+// Consumes `tokens` against `buffer` in order. An `Optional` block is attempted as a whole: if
+// every token inside it matches, the buffer advances past it, otherwise the buffer is left
+// untouched and matching continues with the next token - a failed optional section never errors.
+// In `lenient` mode, whitespace ahead of a non-`Text` token is skipped; `Text` tokens own their
+// whitespace handling entirely (via `match_text_lenient`), since a blanket pre-skip would eat the
+// very whitespace a `Text` literal expects to match itself.
+fn consume_tokens<'a>(buffer: CompleteStr<'a>, tokens: &'a Vec<DateTimePatternToken>, lenient: bool) -> Result<CompleteStr<'a>, String> {
+  let mut buffer = buffer;
+  for token in tokens {
+    if lenient && !is_text_token(token) {
+      buffer = skip_leading_whitespace(buffer);
+    }
+    buffer = match token {
+      DateTimePatternToken::Optional(inner) => consume_tokens(buffer, inner, lenient).unwrap_or(buffer),
+      DateTimePatternToken::Text(t) if lenient => match_text_lenient(buffer, t)?.0,
+      _ => match_token(buffer, token)?.0
+    };
+  }
+  Ok(buffer)
+}
 
-fn validate_datetime_string<'a>(value: &String, pattern_tokens: &Vec<DateTimePatternToken>) -> Result<(), String> {
+fn validate_datetime_string(value: &String, pattern_tokens: &Vec<DateTimePatternToken>, lenient: bool) -> Result<(), String> {
   p!(value);
   p!(pattern_tokens);
-  let mut buffer = CompleteStr(&value);
-  for token in pattern_tokens {
-    let result = match token {
-      DateTimePatternToken::Era => era(buffer),
-      DateTimePatternToken::Year => digit1(buffer),
-      DateTimePatternToken::WeekInYear => week_in_year(buffer),
-      DateTimePatternToken::WeekInMonth => week_in_month(buffer),
-      DateTimePatternToken::DayInYear => day_in_year(buffer),
-      DateTimePatternToken::DayInMonth => day_in_month(buffer),
-      DateTimePatternToken::Month => month(buffer),
-      DateTimePatternToken::Text(t) => text(buffer, t),
-      DateTimePatternToken::DayOfWeekInMonth => digit1(buffer),
-      DateTimePatternToken::DayName => day_of_week_name(buffer),
-      DateTimePatternToken::DayOfWeek => day_of_week(buffer)
-    }.map_err(|err| format!("{:?}", err))?;
-    buffer = result.0;
-  }
-
-  if buffer.len() > 0 {
-    Err(format!("Remaining data after applying pattern {:?}", buffer))
+  let buffer = consume_tokens(CompleteStr(&value), pattern_tokens, lenient)?;
+  let remaining = if lenient { buffer.0.trim() } else { buffer.0 };
+
+  if remaining.len() > 0 {
+    Err(format!("Remaining data after applying pattern {:?}", remaining))
   } else {
     Ok(())
   }
 }
 
 pub fn validate_datetime(value: &String, format: &String) -> Result<(), String> {
-  match parse_pattern(CompleteStr(format.as_str())) {
-    Ok(pattern_tokens) => validate_datetime_string(value, &pattern_tokens.1),
+  validate_datetime_with_options(value, format, MatchOptions::default())
+}
+
+/// As [`validate_datetime`], but resolves ambiguous symbols (currently just `u`) against the
+/// given [`FormatFlavor`] rather than always assuming `SimpleDateFormat`.
+pub fn validate_datetime_with_flavor(value: &String, format: &String, flavor: FormatFlavor) -> Result<(), String> {
+  validate_datetime_with_options(value, format, MatchOptions { flavor, lenient: false })
+}
+
+/// As [`validate_datetime`], but matched according to the given [`MatchOptions`] - in particular,
+/// setting `lenient` tolerates extra whitespace around fields (see [`MatchOptions::lenient`])
+/// instead of requiring an exact match, the way dtparse's fuzzy mode does.
+pub fn validate_datetime_with_options(value: &String, format: &String, options: MatchOptions) -> Result<(), String> {
+  match parse_pattern_with_flavor(CompleteStr(format.as_str()), options.flavor) {
+    Ok(pattern_tokens) => validate_datetime_string(value, &pattern_tokens.1, options.lenient),
+    Err(err) => Err(format!("{:?}", err))
+  }
+}
+
+fn resolve_month(text: &str) -> Result<u32, String> {
+  if let Ok(n) = text.parse::<u32>() {
+    return Ok(n);
+  }
+  let lower = text.to_lowercase();
+  let months = ["january", "february", "march", "april", "may", "june", "july", "august",
+    "september", "october", "november", "december"];
+  months.iter().position(|m| *m == lower || (lower.len() == 3 && m.starts_with(&lower[..])))
+    .map(|i| (i + 1) as u32)
+    .ok_or_else(|| format!("Invalid month '{}'", text))
+}
+
+fn resolve_weekday_name(text: &str) -> Result<Weekday, String> {
+  let lower = text.to_lowercase();
+  let days = [
+    ("sunday", Weekday::Sun), ("monday", Weekday::Mon), ("tuesday", Weekday::Tue),
+    ("wednesday", Weekday::Wed), ("thursday", Weekday::Thu), ("friday", Weekday::Fri),
+    ("saturday", Weekday::Sat)
+  ];
+  days.iter().find(|(name, _)| *name == lower || (lower.len() == 3 && name.starts_with(&lower[..])))
+    .map(|(_, day)| *day)
+    .ok_or_else(|| format!("Invalid day name '{}'", text))
+}
+
+fn weekday_from_iso_number(n: u32) -> Result<Weekday, String> {
+  match n {
+    1 => Ok(Weekday::Mon),
+    2 => Ok(Weekday::Tue),
+    3 => Ok(Weekday::Wed),
+    4 => Ok(Weekday::Thu),
+    5 => Ok(Weekday::Fri),
+    6 => Ok(Weekday::Sat),
+    7 => Ok(Weekday::Sun),
+    _ => Err(format!("Invalid day of week {}", n))
+  }
+}
+
+fn fractional_seconds_to_nanos(text: &str) -> Result<u32, String> {
+  let value = text.parse::<u32>().map_err(|err| format!("{}", err))?;
+  let scale = 9u32.saturating_sub(text.len() as u32);
+  Ok(value * 10u32.pow(scale))
+}
+
+fn parse_fixed_offset(text: &str) -> Result<i32, String> {
+  if text.eq_ignore_ascii_case("z") {
+    return Ok(0);
+  }
+  let (sign, rest) = if let Some(rest) = text.strip_prefix('+') {
+    (1, rest)
+  } else if let Some(rest) = text.strip_prefix('-') {
+    (-1, rest)
+  } else {
+    return Err(format!("Invalid timezone offset '{}'", text));
+  };
+  let digits: String = rest.chars().filter(|ch| ch.is_ascii_digit()).collect();
+  // `gmt_offset` accepts a 1-or-2-digit hour, optionally followed by `:` and a 2-digit minute, so
+  // a single-digit hour (e.g. "+3") or hour+minutes without a leading zero (e.g. "+3:30") need to
+  // parse the same way `validate_datetime` already accepts them.
+  let (hours, minutes) = match digits.len() {
+    1 | 2 => (digits.parse::<i32>().unwrap(), 0),
+    3 => (digits[0..1].parse::<i32>().unwrap(), digits[1..3].parse::<i32>().unwrap()),
+    4 => (digits[0..2].parse::<i32>().unwrap(), digits[2..4].parse::<i32>().unwrap()),
+    _ => return Err(format!("Invalid timezone offset '{}'", text))
+  };
+  Ok(sign * (hours * 3600 + minutes * 60))
+}
+
+fn zone_abbreviation_offset_seconds(name: &str) -> Option<i32> {
+  match name.to_uppercase().as_str() {
+    "PST" => Some(-8 * 3600),
+    "PDT" => Some(-7 * 3600),
+    "MST" => Some(-7 * 3600),
+    "MDT" => Some(-6 * 3600),
+    "CST" => Some(-6 * 3600),
+    "CDT" => Some(-5 * 3600),
+    "EST" => Some(-5 * 3600),
+    "EDT" => Some(-4 * 3600),
+    "UTC" => Some(0),
+    "GMT" => Some(0),
+    _ => None
+  }
+}
+
+// The offset for a general time zone (z) when it can be resolved without knowing the rest of
+// the date (a fixed GMT/UTC offset or a known abbreviation). Returns None for a full IANA zone
+// name, whose offset depends on the date and is resolved once the rest of the fields are known.
+fn general_timezone_offset(text: &str) -> Result<Option<i32>, String> {
+  if let Some(offset) = zone_abbreviation_offset_seconds(text) {
+    return Ok(Some(offset));
+  }
+  let upper = text.to_uppercase();
+  if upper.starts_with("GMT") || upper.starts_with("UTC") {
+    return parse_fixed_offset(&text[3..]).map(Some);
+  }
+  Ok(None)
+}
+
+fn record_token(parsed: &mut Parsed, pending_zone: &mut Option<String>, token: &DateTimePatternToken, text: &str) -> Result<(), String> {
+  let r = |result: chrono::format::ParseResult<()>| result.map_err(|err| format!("{:?}", err));
+  match token {
+    DateTimePatternToken::Era => Ok(()),
+    DateTimePatternToken::Year => r(parsed.set_year(text.parse().map_err(|err: std::num::ParseIntError| err.to_string())?)),
+    DateTimePatternToken::Month => r(parsed.set_month(resolve_month(text)? as i64)),
+    DateTimePatternToken::DayInMonth => r(parsed.set_day(text.parse().map_err(|err: std::num::ParseIntError| err.to_string())?)),
+    DateTimePatternToken::DayInYear => r(parsed.set_ordinal(text.parse().map_err(|err: std::num::ParseIntError| err.to_string())?)),
+    DateTimePatternToken::DayName => r(parsed.set_weekday(resolve_weekday_name(text)?)),
+    DateTimePatternToken::DayOfWeek => r(parsed.set_weekday(weekday_from_iso_number(text.parse().map_err(|err: std::num::ParseIntError| err.to_string())?)?)),
+    DateTimePatternToken::Hour0To23 => r(parsed.set_hour(text.parse().map_err(|err: std::num::ParseIntError| err.to_string())?)),
+    DateTimePatternToken::Hour1To24 => {
+      let hour = text.parse::<i64>().map_err(|err| err.to_string())?;
+      r(parsed.set_hour(hour % 24))
+    },
+    DateTimePatternToken::Hour0To11 => {
+      let hour = text.parse::<i64>().map_err(|err| err.to_string())?;
+      r(parsed.set_hour12(if hour == 0 { 12 } else { hour }))
+    },
+    DateTimePatternToken::Hour1To12 => r(parsed.set_hour12(text.parse().map_err(|err: std::num::ParseIntError| err.to_string())?)),
+    DateTimePatternToken::Minute => r(parsed.set_minute(text.parse().map_err(|err: std::num::ParseIntError| err.to_string())?)),
+    DateTimePatternToken::Second => r(parsed.set_second(text.parse().map_err(|err: std::num::ParseIntError| err.to_string())?)),
+    DateTimePatternToken::FractionalSecond => r(parsed.set_nanosecond(fractional_seconds_to_nanos(text)? as i64)),
+    DateTimePatternToken::AmPm => r(parsed.set_ampm(text.eq_ignore_ascii_case("pm"))),
+    DateTimePatternToken::RFC822TimeZone | DateTimePatternToken::Iso8601TimeZone(_) =>
+      r(parsed.set_offset(parse_fixed_offset(text)? as i64)),
+    DateTimePatternToken::GeneralTimeZone => match general_timezone_offset(text)? {
+      Some(offset) => r(parsed.set_offset(offset as i64)),
+      None => {
+        *pending_zone = Some(text.to_string());
+        Ok(())
+      }
+    },
+    DateTimePatternToken::LocalizedDayOfWeek => {
+      let weekday = match text.parse::<u32>() {
+        Ok(n) => weekday_from_iso_number(n)?,
+        Err(_) => resolve_weekday_name(text)?
+      };
+      r(parsed.set_weekday(weekday))
+    },
+    _ => Ok(())
+  }
+}
+
+fn is_zone_token(token: &DateTimePatternToken) -> bool {
+  match token {
+    DateTimePatternToken::GeneralTimeZone | DateTimePatternToken::RFC822TimeZone | DateTimePatternToken::Iso8601TimeZone(_) => true,
+    _ => false
+  }
+}
+
+// Mirrors `consume_tokens`, but additionally accumulates matched fields into `parsed`. A failed
+// `Optional` block is rolled back in full - including any fields it had started to record - by
+// trying it against a scratch clone of the accumulator before committing. See `consume_tokens`
+// for what `lenient` changes about whitespace handling.
+fn record_tokens<'a>(parsed: &mut Parsed, pending_zone: &mut Option<String>, has_zone: &mut bool, buffer: CompleteStr<'a>, tokens: &'a Vec<DateTimePatternToken>, lenient: bool) -> Result<CompleteStr<'a>, String> {
+  let mut buffer = buffer;
+  for token in tokens {
+    if lenient && !is_text_token(token) {
+      buffer = skip_leading_whitespace(buffer);
+    }
+    buffer = match token {
+      DateTimePatternToken::Optional(inner) => {
+        let mut trial_parsed = parsed.clone();
+        let mut trial_pending_zone = pending_zone.clone();
+        let mut trial_has_zone = *has_zone;
+        match record_tokens(&mut trial_parsed, &mut trial_pending_zone, &mut trial_has_zone, buffer, inner, lenient) {
+          Ok(new_buffer) => {
+            *parsed = trial_parsed;
+            *pending_zone = trial_pending_zone;
+            *has_zone = trial_has_zone;
+            new_buffer
+          },
+          Err(_) => buffer
+        }
+      },
+      DateTimePatternToken::Text(t) if lenient => match_text_lenient(buffer, t)?.0,
+      _ => {
+        let result = match_token(buffer, token)?;
+        record_token(parsed, pending_zone, token, (result.1).0)?;
+        if is_zone_token(token) {
+          *has_zone = true;
+        }
+        result.0
+      }
+    };
+  }
+  Ok(buffer)
+}
+
+fn parse_datetime_tokens(value: &String, pattern_tokens: &Vec<DateTimePatternToken>, lenient: bool) -> Result<(Parsed, bool), String> {
+  let mut parsed = Parsed::new();
+  let mut pending_zone: Option<String> = None;
+  let mut has_zone = false;
+  let buffer = record_tokens(&mut parsed, &mut pending_zone, &mut has_zone, CompleteStr(&value), pattern_tokens, lenient)?;
+  let remaining = if lenient { buffer.0.trim() } else { buffer.0 };
+
+  if remaining.len() > 0 {
+    return Err(format!("Remaining data after applying pattern {:?}", remaining));
+  }
+
+  // `to_naive_time` requires both hour and minute to be set (only second and nanosecond default
+  // to 0), but a pattern with no time-of-day token (e.g. a bare date) never sets either; default
+  // both to midnight.
+  if parsed.hour_div_12.is_none() {
+    parsed.set_hour(0).map_err(|err| format!("{:?}", err))?;
+  }
+  if parsed.minute.is_none() {
+    parsed.set_minute(0).map_err(|err| format!("{:?}", err))?;
+  }
+
+  if let Some(zone_name) = pending_zone {
+    let tz: Tz = Tz::from_str(&zone_name)?;
+    let naive = parsed.to_naive_datetime_with_offset(0).map_err(|err| format!("{:?}", err))?;
+    let offset = tz.offset_from_utc_datetime(&naive).fix();
+    parsed.set_offset(i64::from(offset.local_minus_utc())).map_err(|err| format!("{:?}", err))?;
+  }
+
+  Ok((parsed, has_zone))
+}
+
+/// Parses a value against a pattern, returning the assembled date/time rather than just whether
+/// it matched. When the pattern carries no timezone token, the result falls back to a naive
+/// date/time treated as UTC (`FixedOffset::east(0)`).
+pub fn parse_datetime(value: &String, format: &String) -> Result<DateTime<FixedOffset>, String> {
+  parse_datetime_with_options(value, format, MatchOptions::default())
+}
+
+/// As [`parse_datetime`], but resolves ambiguous symbols (currently just `u`) against the given
+/// [`FormatFlavor`] rather than always assuming `SimpleDateFormat`.
+pub fn parse_datetime_with_flavor(value: &String, format: &String, flavor: FormatFlavor) -> Result<DateTime<FixedOffset>, String> {
+  parse_datetime_with_options(value, format, MatchOptions { flavor, lenient: false })
+}
+
+/// As [`parse_datetime`], but matched according to the given [`MatchOptions`] - in particular,
+/// setting `lenient` tolerates extra whitespace around fields (see [`MatchOptions::lenient`])
+/// instead of requiring an exact match, the way dtparse's fuzzy mode does.
+pub fn parse_datetime_with_options(value: &String, format: &String, options: MatchOptions) -> Result<DateTime<FixedOffset>, String> {
+  match parse_pattern_with_flavor(CompleteStr(format.as_str()), options.flavor) {
+    Ok((_, pattern_tokens)) => {
+      let (parsed, has_zone) = parse_datetime_tokens(value, &pattern_tokens, options.lenient)?;
+      if has_zone {
+        parsed.to_datetime().map_err(|err| format!("{:?}", err))
+      } else {
+        let naive = parsed.to_naive_datetime_with_offset(0).map_err(|err| format!("{:?}", err))?;
+        Ok(DateTime::<FixedOffset>::from_utc(naive, FixedOffset::east(0)))
+      }
+    },
     Err(err) => Err(format!("{:?}", err))
   }
 }
@@ -187,17 +728,19 @@ mod tests {
     expect!(validate_datetime(&"2001-01-02".into(), &"yyyy-MM-dd".into())).to(be_ok());
     expect!(validate_datetime(&"2001-01-02 12:33:45".into(), &"yyyy-MM-dd HH:mm:ss".into())).to(be_ok());
 
-//    "yyyy.MM.dd G 'at' HH:mm:ss z"	2001.07.04 AD at 12:08:56 PDT
+    expect!(validate_datetime(&"2001.07.04 AD at 12:08:56 PDT".into(), &"yyyy.MM.dd G 'at' HH:mm:ss z".into())).to(be_ok());
     expect!(validate_datetime(&"Wed, Jul 4, '01".into(), &"EEE, MMM d, ''yy".into())).to(be_ok());
 
-//    "h:mm a"	12:08 PM
+    expect!(validate_datetime(&"12:08 PM".into(), &"h:mm a".into())).to(be_ok());
+
 //    "hh 'o''clock' a, zzzz"	12 o'clock PM, Pacific Daylight Time
-//    "K:mm a, z"	0:08 PM, PDT
-//    "yyyyy.MMMMM.dd GGG hh:mm aaa"	02001.July.04 AD 12:08 PM
-//    "EEE, d MMM yyyy HH:mm:ss Z"	Wed, 4 Jul 2001 12:08:56 -0700
-//    "yyMMddHHmmssZ"	010704120856-0700
-//    "yyyy-MM-dd'T'HH:mm:ss.SSSZ"	2001-07-04T12:08:56.235-0700
-//    "yyyy-MM-dd'T'HH:mm:ss.SSSXXX"	2001-07-04T12:08:56.235-07:00
+    expect!(validate_datetime(&"0:08 PM, PDT".into(), &"K:mm a, z".into())).to(be_ok());
+    expect!(validate_datetime(&"02001.July.04 AD 12:08 PM".into(), &"yyyyy.MMMMM.dd GGG hh:mm aaa".into())).to(be_ok());
+    expect!(validate_datetime(&"Wed, 4 Jul 2001 12:08:56 -0700".into(), &"EEE, d MMM yyyy HH:mm:ss Z".into())).to(be_ok());
+//    "yyMMddHHmmssZ"	010704120856-0700 (Year is an unbounded digit matcher, so it would
+//    swallow the packed month/day/time digits too; not supported)
+    expect!(validate_datetime(&"2001-07-04T12:08:56.235-0700".into(), &"yyyy-MM-dd'T'HH:mm:ss.SSSZ".into())).to(be_ok());
+    expect!(validate_datetime(&"2001-07-04T12:08:56.235-07:00".into(), &"yyyy-MM-dd'T'HH:mm:ss.SSSXXX".into())).to(be_ok());
 
     expect!(validate_datetime(&"2001-W27-3".into(), &"YYYY-'W'ww-u".into())).to(be_ok());
   }
@@ -260,8 +803,10 @@ mod tests {
 
   #[test]
   fn parse_text() {
-    expect!(parse_pattern(CompleteStr("ello"))).to(
-      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::Text("ello".chars().collect())])));
+    // "loop" avoids every letter the grammar reserves, including the `Qqec` added for
+    // quarter-of-year and localized day-of-week, unlike the plain English word used here before.
+    expect!(parse_pattern(CompleteStr("loop"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::Text("loop".chars().collect())])));
     expect!(parse_pattern(CompleteStr("'dd-MM-yyyy'"))).to(
       be_ok().value((CompleteStr(""), vec![DateTimePatternToken::Text("dd-MM-yyyy".chars().collect())])));
     expect!(parse_pattern(CompleteStr("''"))).to(
@@ -269,8 +814,8 @@ mod tests {
     expect!(parse_pattern(CompleteStr("'dd-''MM''-yyyy'"))).to(
       be_ok().value((CompleteStr(""), vec![DateTimePatternToken::Text("dd-'MM'-yyyy".chars().collect())])));
 
-    expect!(validate_datetime(&"ello".into(), &"ello".into())).to(be_ok());
-    expect!(validate_datetime(&"elo".into(), &"ello".into())).to(be_err());
+    expect!(validate_datetime(&"loop".into(), &"loop".into())).to(be_ok());
+    expect!(validate_datetime(&"lop".into(), &"loop".into())).to(be_err());
     expect!(validate_datetime(&"dd-MM-yyyy".into(), &"'dd-MM-yyyy'".into())).to(be_ok());
   }
 
@@ -321,4 +866,157 @@ mod tests {
     expect!(validate_datetime(&"0".into(), &"u".into())).to(be_err());
   }
 
+  #[test]
+  fn parse_hour_minute_second() {
+    expect!(parse_pattern(CompleteStr("H"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::Hour0To23])));
+    expect!(parse_pattern(CompleteStr("kk"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::Hour1To24])));
+    expect!(parse_pattern(CompleteStr("K"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::Hour0To11])));
+    expect!(parse_pattern(CompleteStr("hh"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::Hour1To12])));
+    expect!(parse_pattern(CompleteStr("mm"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::Minute])));
+    expect!(parse_pattern(CompleteStr("ss"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::Second])));
+    expect!(parse_pattern(CompleteStr("SSS"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::FractionalSecond])));
+
+    expect!(validate_datetime(&"23".into(), &"HH".into())).to(be_ok());
+    expect!(validate_datetime(&"24".into(), &"HH".into())).to(be_err());
+    expect!(validate_datetime(&"24".into(), &"kk".into())).to(be_ok());
+    expect!(validate_datetime(&"0".into(), &"k".into())).to(be_err());
+    expect!(validate_datetime(&"11".into(), &"KK".into())).to(be_ok());
+    expect!(validate_datetime(&"12".into(), &"KK".into())).to(be_err());
+    expect!(validate_datetime(&"12".into(), &"hh".into())).to(be_ok());
+    expect!(validate_datetime(&"0".into(), &"h".into())).to(be_err());
+    expect!(validate_datetime(&"59".into(), &"mm".into())).to(be_ok());
+    expect!(validate_datetime(&"60".into(), &"mm".into())).to(be_err());
+    expect!(validate_datetime(&"55".into(), &"ss".into())).to(be_ok());
+    expect!(validate_datetime(&"978".into(), &"SSS".into())).to(be_ok());
+  }
+
+  #[test]
+  fn parse_am_pm() {
+    expect!(parse_pattern(CompleteStr("a"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::AmPm])));
+
+    expect!(validate_datetime(&"AM".into(), &"a".into())).to(be_ok());
+    expect!(validate_datetime(&"pm".into(), &"a".into())).to(be_ok());
+    expect!(validate_datetime(&"xy".into(), &"a".into())).to(be_err());
+  }
+
+  #[test]
+  fn parse_time_zone() {
+    expect!(parse_pattern(CompleteStr("z"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::GeneralTimeZone])));
+    expect!(parse_pattern(CompleteStr("Z"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::RFC822TimeZone])));
+    expect!(parse_pattern(CompleteStr("XXX"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::Iso8601TimeZone(3)])));
+
+    expect!(validate_datetime(&"-0700".into(), &"Z".into())).to(be_ok());
+    expect!(validate_datetime(&"-700".into(), &"Z".into())).to(be_err());
+
+    expect!(validate_datetime(&"-07".into(), &"X".into())).to(be_ok());
+    expect!(validate_datetime(&"-0700".into(), &"XX".into())).to(be_ok());
+    expect!(validate_datetime(&"-07:00".into(), &"XXX".into())).to(be_ok());
+    expect!(validate_datetime(&"Z".into(), &"XXX".into())).to(be_ok());
+
+    expect!(validate_datetime(&"PST".into(), &"z".into())).to(be_ok());
+    expect!(validate_datetime(&"GMT-08:00".into(), &"z".into())).to(be_ok());
+    expect!(validate_datetime(&"UTC+3".into(), &"z".into())).to(be_ok());
+    expect!(validate_datetime(&"America/New_York".into(), &"z".into())).to(be_ok());
+    expect!(validate_datetime(&"Not/AZone".into(), &"z".into())).to(be_err());
+
+    // parse_datetime must accept everything validate_datetime does for the same pattern/value,
+    // including single-digit-hour general zones like "UTC+3".
+    let result = parse_datetime(&"2001-07-04T12:08:56 UTC+3".into(), &"yyyy-MM-dd'T'HH:mm:ss z".into());
+    expect!(result.clone()).to(be_ok());
+    expect!(result.unwrap().offset().local_minus_utc()).to(be_equal_to(3 * 3600));
+  }
+
+  #[test]
+  fn parse_datetime_with_offset() {
+    let result = parse_datetime(&"2001-07-04T12:08:56.235-07:00".into(), &"yyyy-MM-dd'T'HH:mm:ss.SSSXXX".into());
+    expect!(result.clone()).to(be_ok());
+    let dt = result.unwrap();
+    expect!(dt.naive_local().to_string()).to(be_equal_to("2001-07-04 12:08:56.235"));
+    expect!(dt.offset().local_minus_utc()).to(be_equal_to(-7 * 3600));
+  }
+
+  #[test]
+  fn parse_datetime_without_offset_defaults_to_utc() {
+    let result = parse_datetime(&"2001-01-02".into(), &"yyyy-MM-dd".into());
+    expect!(result.clone()).to(be_ok());
+    expect!(result.unwrap().offset().local_minus_utc()).to(be_equal_to(0));
+  }
+
+  #[test]
+  fn parse_datetime_rejects_impossible_date() {
+    expect!(parse_datetime(&"2001-02-30".into(), &"yyyy-MM-dd".into())).to(be_err());
+  }
+
+  #[test]
+  fn parse_optional_section() {
+    expect!(parse_pattern(CompleteStr("[.SSS]"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::Optional(
+        vec![DateTimePatternToken::Text(".".chars().collect()), DateTimePatternToken::FractionalSecond])])));
+
+    let pattern = "yyyy-MM-dd'T'HH:mm:ss[.SSS][XXX]".to_string();
+    expect!(validate_datetime(&"2001-07-04T12:08:56".into(), &pattern)).to(be_ok());
+    expect!(validate_datetime(&"2001-07-04T12:08:56.235".into(), &pattern)).to(be_ok());
+    expect!(validate_datetime(&"2001-07-04T12:08:56-07:00".into(), &pattern)).to(be_ok());
+    expect!(validate_datetime(&"2001-07-04T12:08:56.235-07:00".into(), &pattern)).to(be_ok());
+    expect!(validate_datetime(&"2001-07-04T12:08:56.abc".into(), &pattern)).to(be_err());
+  }
+
+  #[test]
+  fn parse_quarter_and_localized_day_of_week() {
+    expect!(parse_pattern(CompleteStr("Q"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::Quarter])));
+    expect!(parse_pattern(CompleteStr("qq"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::Quarter])));
+    expect!(parse_pattern(CompleteStr("e"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::LocalizedDayOfWeek])));
+    expect!(parse_pattern(CompleteStr("cc"))).to(
+      be_ok().value((CompleteStr(""), vec![DateTimePatternToken::LocalizedDayOfWeek])));
+
+    expect!(validate_datetime(&"3".into(), &"Q".into())).to(be_ok());
+    expect!(validate_datetime(&"Q3".into(), &"Q".into())).to(be_ok());
+    expect!(validate_datetime(&"3rd quarter".into(), &"QQQQ".into())).to(be_ok());
+    expect!(validate_datetime(&"5".into(), &"Q".into())).to(be_err());
+
+    expect!(validate_datetime(&"3".into(), &"e".into())).to(be_ok());
+    expect!(validate_datetime(&"Tue".into(), &"e".into())).to(be_ok());
+  }
+
+  #[test]
+  fn format_flavor_switches_u_between_day_of_week_and_year() {
+    expect!(validate_datetime(&"3".into(), &"u".into())).to(be_ok());
+    expect!(validate_datetime_with_flavor(&"3".into(), &"u".into(), FormatFlavor::SimpleDateFormat)).to(be_ok());
+    expect!(validate_datetime_with_flavor(&"2001".into(), &"u".into(), FormatFlavor::DateTimeFormatter)).to(be_ok());
+
+    let simple = parse_datetime_with_flavor(&"3".into(), &"u".into(), FormatFlavor::SimpleDateFormat);
+    expect!(simple).to(be_err());
+    let java_time = parse_datetime_with_flavor(&"2001-01-02".into(), &"uuuu-MM-dd".into(), FormatFlavor::DateTimeFormatter);
+    expect!(java_time).to(be_ok());
+  }
+
+  #[test]
+  fn lenient_match_tolerates_surrounding_and_extra_whitespace() {
+    let pattern = "EEE, MMM d, ''yy".to_string();
+    let value = "  Wed, Jul 4,  '01 ".to_string();
+
+    expect!(validate_datetime(&value, &pattern)).to(be_err());
+    expect!(validate_datetime_with_options(&value, &pattern, MatchOptions { lenient: true, ..MatchOptions::default() })).to(be_ok());
+
+    let parsed = parse_datetime_with_options(&value, &pattern, MatchOptions { lenient: true, ..MatchOptions::default() });
+    expect!(parsed).to(be_ok());
+
+    expect!(validate_datetime(&" 2001".into(), &"yyyy".into())).to(be_err());
+    expect!(validate_datetime_with_options(&" 2001".into(), &"yyyy".into(), MatchOptions { lenient: true, ..MatchOptions::default() })).to(be_ok());
+  }
+
 }
\ No newline at end of file